@@ -1,39 +1,123 @@
 use prettytable::{color, format::Alignment, Attr, Cell, Row, Table};
 use rand::prelude::*;
 use std::cell::RefCell;
-use std::rc::Rc;
+use std::fmt::Debug;
+use std::io::{self, Read, Write};
+use std::rc::{Rc, Weak};
 
-type RealNode = Rc<RefCell<Node>>;
-type Link = Option<Rc<RefCell<Node>>>;
+type RealNode<K, V> = Rc<RefCell<Node<K, V>>>;
+type Link<K, V> = Option<RealNode<K, V>>;
+// A weak back-pointer: unlike `Link`, it doesn't keep a node alive by
+// itself, so a forward `Rc` chain paired with backward `Weak` links can
+// never form a reference cycle that leaks memory.
+type PrevLink<K, V> = Option<Weak<RefCell<Node<K, V>>>>;
+
+/// Byte encoding for keys/values stored by [`SkipList::flush`] and
+/// [`SkipList::load`]. Implement this for any type that should be
+/// persistable as a skip list entry.
+pub trait Codec: Sized {
+    fn to_bytes(&self) -> Vec<u8>;
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self>;
+}
+
+impl Codec for u64 {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.to_be_bytes().to_vec()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let arr: [u8; 8] = bytes
+            .try_into()
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "expected 8 bytes for u64"))?;
+        Ok(u64::from_be_bytes(arr))
+    }
+}
+
+impl Codec for String {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        String::from_utf8(bytes.to_vec()).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
 
 #[derive(Debug, Clone)]
-pub struct Node {
-    data: String,
-    next: Vec<Link>,
-    offset: u64,
-    pos: u64,
+pub struct Node<K, V> {
+    value: V,
+    next: Vec<Link<K, V>>,
+    // `spans[level]` is the number of level-0 nodes `next[level]` jumps over,
+    // counting the destination node itself (so an immediate neighbor has a
+    // span of 1). Kept in lockstep with `next` so order statistics (`select`,
+    // `rank`) can walk the express lanes without visiting every node.
+    spans: Vec<u64>,
+    // Level-0 back-pointer, `None` for the head. Only level 0 needs a
+    // predecessor link: reverse walks step one node at a time, so the
+    // express lanes used for forward descent don't help going backward.
+    prev: PrevLink<K, V>,
+    key: K,
 }
 
-impl Node {
-    fn new(next: Vec<Link>, offset: u64, data: String, pos: u64) -> RealNode {
+impl<K, V> Node<K, V> {
+    fn new(next: Vec<Link<K, V>>, key: K, value: V) -> RealNode<K, V> {
+        let spans = vec![0; next.len()];
         Rc::new(RefCell::new(Node {
             next,
-            offset,
-            data,
-            pos,
+            spans,
+            prev: None,
+            key,
+            value,
         }))
     }
 }
 
+struct RangeIter<K, V> {
+    cur: Link<K, V>,
+    end: K,
+}
+
+impl<K: Ord + Clone, V: Clone> Iterator for RangeIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.cur.take()?;
+        let node = node.borrow();
+        if node.key > self.end {
+            return None;
+        }
+        self.cur = node.next[0].clone();
+        Some((node.key.clone(), node.value.clone()))
+    }
+}
+
+struct RevIter<K, V> {
+    cur: Link<K, V>,
+}
+
+impl<K: Clone, V: Clone> Iterator for RevIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.cur.take()?;
+        let node_ref = node.borrow();
+        let result = (node_ref.key.clone(), node_ref.value.clone());
+        let prev = node_ref.prev.as_ref().and_then(Weak::upgrade);
+        drop(node_ref);
+        self.cur = prev;
+        Some(result)
+    }
+}
+
 #[derive(Debug, Clone)]
-pub struct SkipList {
-    head: Link,
-    tails: Vec<Link>,
+pub struct SkipList<K, V> {
+    head: Link<K, V>,
+    tails: Vec<Link<K, V>>,
     max_level_idx: usize,
     length: u64,
 }
 
-impl SkipList {
+impl<K: Ord + Clone, V: Clone> SkipList<K, V> {
     pub fn new(level: usize) -> Self {
         SkipList {
             head: None,
@@ -52,17 +136,32 @@ impl SkipList {
         n
     }
 
-    pub fn append(&mut self, offset: u64, data: String) {
+    /// Appends `key` as the new last element. This is the fast path for
+    /// monotonically increasing keys; it does not check ordering against the
+    /// current tail, so callers that can't guarantee that should use
+    /// [`SkipList::insert`] instead.
+    pub fn append(&mut self, key: K, value: V) {
         let level = 1 + if self.head.is_none() {
             self.max_level_idx
         } else {
             self.get_level()
         };
-        let node = Node::new(vec![None; level], offset, data, self.length);
+        let prev_tail = self.tails[0].clone();
+        let node = Node::new(vec![None; level], key, value);
+        node.borrow_mut().prev = prev_tail.map(|p| Rc::downgrade(&p));
         for i in 0..level {
             if let Some(old) = self.tails[i].take() {
-                let next = &mut old.borrow_mut().next;
-                next[i] = Some(node.clone());
+                // Appending always extends the far end, so the only span that
+                // changes is the one from the old tail to this new node: the
+                // gap between their positions in the level-0 chain. The old
+                // tail's rank is found via the span-accumulating descent
+                // (O(log n)) rather than a stored position, since earlier
+                // inserts/removes elsewhere in the list can shift it.
+                let old_rank = self.rank(old.borrow().key.clone()).unwrap_or(0);
+                let span = self.length - old_rank;
+                let mut old = old.borrow_mut();
+                old.next[i] = Some(node.clone());
+                old.spans[i] = span;
             }
             self.tails[i] = Some(node.clone());
         }
@@ -73,7 +172,415 @@ impl SkipList {
         self.length += 1;
     }
 
-    pub fn level_path(&self, offset: u64, found_level: usize) {
+    /// Inserts `key` at its sorted position, searching for the correct spot
+    /// rather than assuming keys arrive in order. Overwrites the value in
+    /// place if `key` is already present.
+    pub fn insert(&mut self, key: K, value: V) {
+        let head = match self.head.clone() {
+            None => {
+                self.append(key, value);
+                return;
+            }
+            Some(head) => head,
+        };
+
+        if key == head.borrow().key {
+            head.borrow_mut().value = value;
+            return;
+        }
+        if key < head.borrow().key {
+            self.insert_before_head(key, value);
+            return;
+        }
+
+        // Descend from the top level, recording the rightmost node at each
+        // level that sorts before `key`, along with its level-0 rank, so
+        // spans can be split once the insertion point is known.
+        let mut update: Vec<RealNode<K, V>> = Vec::with_capacity(self.max_level_idx + 1);
+        let mut rank = vec![0u64; self.max_level_idx + 1];
+        let mut cur = head;
+        let mut traversed = 0u64;
+
+        for level in (0..=self.max_level_idx).rev() {
+            loop {
+                let next = cur.borrow().next.get(level).cloned().flatten();
+                match next {
+                    Some(n) if n.borrow().key < key => {
+                        traversed += cur.borrow().spans.get(level).copied().unwrap_or(0);
+                        cur = n;
+                    }
+                    Some(n) if n.borrow().key == key => {
+                        n.borrow_mut().value = value;
+                        return;
+                    }
+                    _ => break,
+                }
+            }
+            rank[level] = traversed;
+            update.push(cur.clone());
+        }
+        update.reverse();
+
+        let height = 1 + self.get_level();
+        let mut node_next = vec![None; height];
+        let mut node_spans = vec![0u64; height];
+
+        for (level, node_next_slot) in node_next.iter_mut().enumerate().take(height) {
+            let pred = &update[level];
+            let pred_next_at_level = pred.borrow().next.get(level).cloned().flatten();
+            let pred_span = pred.borrow().spans.get(level).copied().unwrap_or(0);
+            let rank_diff = rank[0] - rank[level];
+
+            node_spans[level] = if pred_next_at_level.is_some() {
+                pred_span - rank_diff
+            } else {
+                0
+            };
+            *node_next_slot = pred_next_at_level;
+            pred.borrow_mut().spans[level] = rank_diff + 1;
+        }
+
+        let node = Node::new(node_next, key, value);
+        node.borrow_mut().spans = node_spans;
+        node.borrow_mut().prev = Some(Rc::downgrade(&update[0]));
+        if let Some(succ) = node.borrow().next[0].clone() {
+            succ.borrow_mut().prev = Some(Rc::downgrade(&node));
+        }
+
+        for (level, pred) in update.iter().enumerate().take(height) {
+            pred.borrow_mut().next[level] = Some(node.clone());
+            if node.borrow().next[level].is_none() {
+                self.tails[level] = Some(node.clone());
+            }
+        }
+        for (level, pred) in update.iter().enumerate().skip(height) {
+            let mut pred_mut = pred.borrow_mut();
+            if pred_mut.next[level].is_some() {
+                pred_mut.spans[level] += 1;
+            }
+        }
+
+        self.length += 1;
+    }
+
+    fn insert_before_head(&mut self, key: K, value: V) {
+        let old_head = self.head.clone().unwrap();
+        // `append`'s first-node case, this function and `remove_head` (which
+        // extends the promoted node's vectors) are the only ways a head gets
+        // built, and all three always give it a full `max_level_idx + 1`
+        // tower, so every level here points straight at the old head with a
+        // span of 1 — there's no shorter-than-full head to fall back to.
+        debug_assert_eq!(old_head.borrow().next.len(), self.max_level_idx + 1);
+
+        let next = vec![Some(old_head.clone()); self.max_level_idx + 1];
+        let spans = vec![1; self.max_level_idx + 1];
+
+        let node = Node::new(next, key, value);
+        node.borrow_mut().spans = spans;
+        old_head.borrow_mut().prev = Some(Rc::downgrade(&node));
+
+        self.head = Some(node);
+        self.length += 1;
+    }
+
+    pub fn remove(&mut self, key: K) -> Option<V> {
+        let head = self.head.clone()?;
+
+        if head.borrow().key == key {
+            return self.remove_head();
+        }
+
+        // Descend from the top level, recording the rightmost node at each
+        // level whose next pointer must be rewired once the target is found.
+        let mut update: Vec<RealNode<K, V>> = Vec::with_capacity(self.max_level_idx + 1);
+        let mut cur = head;
+        let mut target: Link<K, V> = None;
+
+        for level in (0..=self.max_level_idx).rev() {
+            loop {
+                let next = cur.borrow().next[level].clone();
+                match next {
+                    Some(n) if n.borrow().key < key => cur = n,
+                    Some(n) if n.borrow().key == key => {
+                        target = Some(n);
+                        break;
+                    }
+                    _ => break,
+                }
+            }
+            update.push(cur.clone());
+        }
+        update.reverse();
+
+        let target = target?;
+        // Clone the target's own next pointers/spans before mutating any
+        // neighbor, so no `Rc<RefCell<Node>>` borrow from `target` is held
+        // while we borrow_mut() the predecessors below.
+        let target_next = target.borrow().next.clone();
+        let target_spans = target.borrow().spans.clone();
+
+        for level in 0..=self.max_level_idx {
+            let pred = &update[level];
+            if level < target_next.len() {
+                // `pred` points directly at `target` here: splice it out and
+                // merge the two spans (minus the node that just disappeared).
+                let next_at_level = target_next[level].clone();
+                let mut pred_mut = pred.borrow_mut();
+                pred_mut.next[level] = next_at_level.clone();
+                pred_mut.spans[level] = match next_at_level {
+                    Some(_) => pred_mut.spans[level] + target_spans[level] - 1,
+                    None => 0,
+                };
+                drop(pred_mut);
+                if self.tails[level]
+                    .as_ref()
+                    .is_some_and(|t| Rc::ptr_eq(t, &target))
+                {
+                    self.tails[level] = Some(pred.clone());
+                }
+            } else {
+                // `target` isn't present at this level, but `pred`'s pointer
+                // still jumps over its position, so the span shrinks by one.
+                let mut pred_mut = pred.borrow_mut();
+                if pred_mut.next[level].is_some() {
+                    pred_mut.spans[level] -= 1;
+                }
+            }
+        }
+
+        if let Some(succ) = target_next[0].clone() {
+            succ.borrow_mut().prev = Some(Rc::downgrade(&update[0]));
+        }
+
+        self.length -= 1;
+
+        let value = target.borrow().value.clone();
+        Some(value)
+    }
+
+    fn remove_head(&mut self) -> Option<V> {
+        let old_head = self.head.clone()?;
+        let value = old_head.borrow().value.clone();
+        let old_next = old_head.borrow().next.clone();
+        let old_spans = old_head.borrow().spans.clone();
+
+        match old_next[0].clone() {
+            Some(new_head) => {
+                // The new head takes over as the traversal root, so it needs
+                // the old head's full level vector: keep its own pointers up
+                // to its natural height, then fall back to the old head's
+                // pointers (with spans shortened by the node that dropped
+                // out) for the levels it didn't itself reach.
+                let own_height = new_head.borrow().next.len();
+                let mut extended_next = new_head.borrow().next.clone();
+                extended_next.extend(old_next.iter().skip(own_height).cloned());
+                let mut extended_spans = new_head.borrow().spans.clone();
+                extended_spans.extend(
+                    old_spans
+                        .iter()
+                        .skip(own_height)
+                        .map(|&s| s.saturating_sub(1)),
+                );
+
+                let mut new_head_mut = new_head.borrow_mut();
+                new_head_mut.next = extended_next;
+                new_head_mut.spans = extended_spans;
+                new_head_mut.prev = None;
+                drop(new_head_mut);
+
+                for tail in self.tails.iter_mut() {
+                    if tail.as_ref().is_some_and(|t| Rc::ptr_eq(t, &old_head)) {
+                        *tail = Some(new_head.clone());
+                    }
+                }
+                self.head = Some(new_head);
+            }
+            None => {
+                self.head = None;
+                for tail in self.tails.iter_mut() {
+                    *tail = None;
+                }
+            }
+        }
+
+        self.length -= 1;
+        Some(value)
+    }
+
+    pub fn range(&self, start: K, end: K) -> impl Iterator<Item = (K, V)> {
+        let head = match self.head.clone() {
+            Some(head) => head,
+            None => return RangeIter { cur: None, end },
+        };
+
+        let mut start_level = self.max_level_idx;
+        while start_level > 0 && head.borrow().next[start_level].is_none() {
+            start_level -= 1;
+        }
+
+        let mut n = head;
+        for level in (0..=start_level).rev() {
+            loop {
+                let next = n.borrow().next[level].clone();
+                match next {
+                    Some(tmp) if tmp.borrow().key < start => n = tmp,
+                    _ => break,
+                }
+            }
+        }
+
+        // `n` is the rightmost node with key < start; step right once more
+        // at level 0 unless `n` itself already lands inside the range.
+        let cur = if n.borrow().key >= start {
+            Some(n)
+        } else {
+            n.borrow().next[0].clone()
+        };
+
+        RangeIter { cur, end }
+    }
+
+    /// Returns the greatest entry with a key strictly less than `key` (the
+    /// predecessor, whether or not `key` itself is present).
+    pub fn find_prev(&self, key: K) -> Option<(K, V)> {
+        let head = self.head.clone()?;
+        if head.borrow().key >= key {
+            return None;
+        }
+
+        let mut start_level = self.max_level_idx;
+        while start_level > 0 && head.borrow().next[start_level].is_none() {
+            start_level -= 1;
+        }
+
+        let mut n = head;
+        for level in (0..=start_level).rev() {
+            loop {
+                let next = n.borrow().next[level].clone();
+                match next {
+                    Some(tmp) if tmp.borrow().key < key => n = tmp,
+                    _ => break,
+                }
+            }
+        }
+
+        let node = n.borrow();
+        Some((node.key.clone(), node.value.clone()))
+    }
+
+    /// Walks the list back-to-front via the level-0 `prev` links, starting
+    /// at the tail. Pairs with `range`/`find_prev` for cursors that page in
+    /// either direction.
+    pub fn iter_rev(&self) -> impl Iterator<Item = (K, V)> {
+        RevIter {
+            cur: self.tails[0].clone(),
+        }
+    }
+
+    pub fn find(&self, key: K) -> Option<(V, usize)> {
+        match self.head {
+            Some(ref head) => {
+                let mut start_level = self.max_level_idx;
+                let node = head.clone();
+                let mut result = None;
+
+                while start_level > 0 && node.borrow().next[start_level].is_none() {
+                    start_level -= 1;
+                }
+                let mut n = node;
+                for level in (0..=start_level).rev() {
+                    loop {
+                        let next = n.clone();
+                        match next.borrow().next[level] {
+                            Some(ref tmp) => {
+                                if tmp.borrow().key <= key {
+                                    n = tmp.clone();
+                                } else {
+                                    break;
+                                }
+                            }
+                            _ => break,
+                        };
+                    }
+                    if n.borrow().key == key {
+                        let tmp = n.borrow();
+                        result = Some((tmp.value.clone(), level));
+                        break;
+                    }
+                }
+                result
+            }
+            None => None,
+        }
+    }
+
+    /// Returns the `k`-th element (0-indexed) by descending the express
+    /// lanes and accumulating spans, in O(log n).
+    pub fn select(&self, k: u64) -> Option<(K, V)> {
+        if k >= self.length {
+            return None;
+        }
+        let mut cur = self.head.clone()?;
+        let mut traversed = 0u64;
+
+        for level in (0..=self.max_level_idx).rev() {
+            loop {
+                let (next, span) = {
+                    let node = cur.borrow();
+                    (
+                        node.next.get(level).cloned().flatten(),
+                        node.spans.get(level).copied().unwrap_or(0),
+                    )
+                };
+                match next {
+                    Some(nxt) if traversed + span <= k => {
+                        traversed += span;
+                        cur = nxt;
+                    }
+                    _ => break,
+                }
+            }
+        }
+
+        let node = cur.borrow();
+        Some((node.key.clone(), node.value.clone()))
+    }
+
+    /// Returns the 0-indexed rank of `key`, i.e. how many elements sort
+    /// before it, by the same span-accumulating descent as `select`.
+    pub fn rank(&self, key: K) -> Option<u64> {
+        let mut cur = self.head.clone()?;
+        let mut traversed = 0u64;
+
+        for level in (0..=self.max_level_idx).rev() {
+            loop {
+                let (next, span) = {
+                    let node = cur.borrow();
+                    (
+                        node.next.get(level).cloned().flatten(),
+                        node.spans.get(level).copied().unwrap_or(0),
+                    )
+                };
+                match next {
+                    Some(nxt) if nxt.borrow().key <= key => {
+                        traversed += span;
+                        cur = nxt;
+                    }
+                    _ => break,
+                }
+            }
+        }
+
+        if cur.borrow().key == key {
+            Some(traversed)
+        } else {
+            None
+        }
+    }
+}
+
+impl<K: Ord + Clone + Debug, V: Clone + Debug> SkipList<K, V> {
+    pub fn level_path(&self, key: K, found_level: usize) {
         // Create the table
         let mut table = Table::new();
         if let Some(ref head) = self.head {
@@ -87,19 +594,24 @@ impl SkipList {
                 loop {
                     let next = n.clone();
 
-                    let mut color = if level == found_level && next.borrow().offset <= offset {
+                    let mut color = if level == found_level && next.borrow().key <= key {
                         color::RED
                     } else {
                         color::WHITE
                     };
-                    while next.borrow().pos > pos {
+                    // Nodes absent from this level still occupy a slot in the
+                    // full level-0 chain; their rank (found lazily via the
+                    // span-accumulating descent) tells us how many filler
+                    // arrows to draw before this node's own cell.
+                    let next_rank = self.rank(next.borrow().key.clone()).unwrap_or(pos);
+                    while next_rank > pos {
                         cells.push(
                             Cell::new_align("->", Alignment::CENTER)
                                 .with_style(Attr::ForegroundColor(color)),
                         );
                         pos += 1;
                     }
-                    color = if next.borrow().offset == offset {
+                    color = if next.borrow().key == key {
                         color::GREEN
                     } else {
                         color
@@ -107,9 +619,9 @@ impl SkipList {
                     cells.push(
                         Cell::new(
                             format!(
-                                "offset={:?}, data={:?}",
-                                next.borrow().offset,
-                                next.borrow().data
+                                "key={:?}, value={:?}",
+                                next.borrow().key,
+                                next.borrow().value
                             )
                             .as_str(),
                         )
@@ -132,45 +644,58 @@ impl SkipList {
             table.printstd();
         }
     }
+}
 
-    pub fn find(&self, offset: u64) -> Option<(String, usize)> {
-        match self.head {
-            Some(ref head) => {
-                let mut start_level = self.max_level_idx;
-                let node = head.clone();
-                let mut result = None;
+impl<K: Ord + Clone + Codec, V: Clone + Codec> SkipList<K, V> {
+    /// Serializes the list as a header (`length`, `max_level_idx`) followed
+    /// by length-prefixed `(key, value)` records in level-0 order, so it can
+    /// be rebuilt with [`SkipList::load`] after a restart.
+    pub fn flush<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        w.write_all(&self.length.to_be_bytes())?;
+        w.write_all(&(self.max_level_idx as u64).to_be_bytes())?;
 
-                loop {
-                    if node.borrow().next[start_level].is_some() {
-                        break;
-                    }
-                    start_level -= 1;
-                }
-                let mut n = node;
-                for level in (0..=start_level).rev() {
-                    loop {
-                        let next = n.clone();
-                        match next.borrow().next[level] {
-                            Some(ref tmp) => {
-                                if tmp.borrow().offset <= offset {
-                                    n = tmp.clone();
-                                } else {
-                                    break;
-                                }
-                            }
-                            _ => break,
-                        };
-                    }
-                    if n.borrow().offset == offset {
-                        let tmp = n.borrow();
-                        result = Some((tmp.data.clone(), level));
-                        break;
-                    }
-                }
-                result
-            }
-            None => None,
+        let mut cur = self.head.clone();
+        while let Some(node) = cur {
+            let node_ref = node.borrow();
+            let key_bytes = node_ref.key.to_bytes();
+            let value_bytes = node_ref.value.to_bytes();
+            w.write_all(&(key_bytes.len() as u32).to_be_bytes())?;
+            w.write_all(&key_bytes)?;
+            w.write_all(&(value_bytes.len() as u32).to_be_bytes())?;
+            w.write_all(&value_bytes)?;
+            let next = node_ref.next[0].clone();
+            drop(node_ref);
+            cur = next;
+        }
+        Ok(())
+    }
+
+    /// Rebuilds a `SkipList` from a stream written by [`SkipList::flush`].
+    /// Entries are replayed through `append` in their stored order, so
+    /// levels are re-randomized rather than restored verbatim.
+    pub fn load<R: Read>(r: &mut R) -> io::Result<Self> {
+        let mut u64_buf = [0u8; 8];
+        r.read_exact(&mut u64_buf)?;
+        let length = u64::from_be_bytes(u64_buf);
+        r.read_exact(&mut u64_buf)?;
+        let max_level_idx = u64::from_be_bytes(u64_buf) as usize;
+
+        let mut list = SkipList::new(max_level_idx + 1);
+        let mut len_buf = [0u8; 4];
+        for _ in 0..length {
+            r.read_exact(&mut len_buf)?;
+            let mut key_bytes = vec![0u8; u32::from_be_bytes(len_buf) as usize];
+            r.read_exact(&mut key_bytes)?;
+            let key = K::from_bytes(&key_bytes)?;
+
+            r.read_exact(&mut len_buf)?;
+            let mut value_bytes = vec![0u8; u32::from_be_bytes(len_buf) as usize];
+            r.read_exact(&mut value_bytes)?;
+            let value = V::from_bytes(&value_bytes)?;
+
+            list.append(key, value);
         }
+        Ok(list)
     }
 }
 
@@ -187,7 +712,7 @@ mod tests {
     }
     #[test]
     fn run_test() {
-        let mut skip_list = SkipList::new(6);
+        let mut skip_list: SkipList<u64, String> = SkipList::new(6);
         let mut generate_values = vec![];
         for i in 0..10 {
             generate_values.push(generate(6));
@@ -200,4 +725,187 @@ mod tests {
             assert_eq!(data, generate_values[offset as usize])
         }
     }
+
+    #[test]
+    fn test_remove() {
+        let mut skip_list: SkipList<u64, String> = SkipList::new(6);
+        let mut generate_values = vec![];
+        for i in 0..10 {
+            generate_values.push(generate(6));
+            skip_list.append(i, generate_values[i as usize].clone());
+        }
+
+        // Remove an interior node, the tail, and finally the head.
+        assert_eq!(skip_list.remove(5), Some(generate_values[5].clone()));
+        assert_eq!(skip_list.find(5), None);
+        assert_eq!(skip_list.length, 9);
+
+        assert_eq!(skip_list.remove(9), Some(generate_values[9].clone()));
+        assert_eq!(skip_list.find(9), None);
+
+        assert_eq!(skip_list.remove(0), Some(generate_values[0].clone()));
+        assert_eq!(skip_list.find(0), None);
+        assert_eq!(
+            skip_list.find(1).map(|(data, _)| data),
+            Some(generate_values[1].clone())
+        );
+
+        assert_eq!(skip_list.remove(42), None);
+    }
+
+    #[test]
+    fn test_find_single_element() {
+        let mut skip_list: SkipList<u64, String> = SkipList::new(6);
+        let value = generate(6);
+        skip_list.append(0, value.clone());
+        assert_eq!(skip_list.find(0).map(|(data, _)| data), Some(value));
+
+        // Shrinking a larger list down to one element must not leave `find`
+        // walking off the head's empty upper levels.
+        let mut skip_list: SkipList<u64, String> = SkipList::new(6);
+        let mut generate_values = vec![];
+        for i in 0..5 {
+            generate_values.push(generate(6));
+            skip_list.append(i, generate_values[i as usize].clone());
+        }
+        for i in 0..4 {
+            skip_list.remove(i);
+        }
+        assert_eq!(
+            skip_list.find(4).map(|(data, _)| data),
+            Some(generate_values[4].clone())
+        );
+    }
+
+    #[test]
+    fn test_range() {
+        let mut skip_list: SkipList<u64, String> = SkipList::new(6);
+        let mut generate_values = vec![];
+        for i in 0..10 {
+            generate_values.push(generate(6));
+            skip_list.append(i, generate_values[i as usize].clone());
+        }
+
+        let collected: Vec<(u64, String)> = skip_list.range(3, 6).collect();
+        let expected: Vec<(u64, String)> = (3..=6)
+            .map(|i| (i, generate_values[i as usize].clone()))
+            .collect();
+        assert_eq!(collected, expected);
+
+        assert_eq!(skip_list.range(3, 6).take(2).count(), 2);
+        assert!(skip_list.range(20, 30).next().is_none());
+    }
+
+    #[test]
+    fn test_select_and_rank() {
+        let mut skip_list: SkipList<u64, String> = SkipList::new(6);
+        let mut generate_values = vec![];
+        for i in 0..10 {
+            generate_values.push(generate(6));
+            skip_list.append(i, generate_values[i as usize].clone());
+        }
+
+        for k in 0..10u64 {
+            assert_eq!(
+                skip_list.select(k),
+                Some((k, generate_values[k as usize].clone()))
+            );
+            assert_eq!(skip_list.rank(k), Some(k));
+        }
+        assert_eq!(skip_list.select(10), None);
+        assert_eq!(skip_list.rank(42), None);
+
+        skip_list.remove(3);
+        assert_eq!(skip_list.select(3), Some((4, generate_values[4].clone())));
+        assert_eq!(skip_list.rank(4), Some(3));
+    }
+
+    #[test]
+    fn test_insert_generic_keys() {
+        // `insert` works for non-integer, non-monotonic keys too.
+        let mut skip_list: SkipList<String, u32> = SkipList::new(4);
+        skip_list.insert("banana".to_string(), 2);
+        skip_list.insert("apple".to_string(), 1);
+        skip_list.insert("cherry".to_string(), 3);
+        skip_list.insert("apple".to_string(), 10); // overwrite
+
+        assert_eq!(
+            skip_list.find("apple".to_string()).map(|(v, _)| v),
+            Some(10)
+        );
+        assert_eq!(
+            skip_list
+                .range("apple".to_string(), "cherry".to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                ("apple".to_string(), 10),
+                ("banana".to_string(), 2),
+                ("cherry".to_string(), 3),
+            ]
+        );
+        assert_eq!(skip_list.select(1), Some(("banana".to_string(), 2)));
+        assert_eq!(skip_list.rank("cherry".to_string()), Some(2));
+
+        assert_eq!(skip_list.remove("banana".to_string()), Some(2),);
+        assert_eq!(
+            skip_list
+                .range("apple".to_string(), "cherry".to_string())
+                .collect::<Vec<_>>(),
+            vec![("apple".to_string(), 10), ("cherry".to_string(), 3)]
+        );
+    }
+
+    #[test]
+    fn test_flush_and_load() {
+        let mut skip_list: SkipList<u64, String> = SkipList::new(6);
+        let mut generate_values = vec![];
+        for i in 0..10 {
+            generate_values.push(generate(6));
+            skip_list.append(i, generate_values[i as usize].clone());
+        }
+        skip_list.remove(4);
+
+        let mut buf = Vec::new();
+        skip_list.flush(&mut buf).unwrap();
+
+        let loaded: SkipList<u64, String> = SkipList::load(&mut buf.as_slice()).unwrap();
+        assert_eq!(loaded.length, skip_list.length);
+        assert_eq!(
+            loaded.range(0, 9).collect::<Vec<_>>(),
+            skip_list.range(0, 9).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_find_prev_and_iter_rev() {
+        let mut skip_list: SkipList<u64, String> = SkipList::new(6);
+        let mut generate_values = vec![];
+        for i in 0..10 {
+            generate_values.push(generate(6));
+            skip_list.append(i, generate_values[i as usize].clone());
+        }
+
+        assert_eq!(
+            skip_list.find_prev(5),
+            Some((4, generate_values[4].clone()))
+        );
+        assert_eq!(skip_list.find_prev(0), None);
+
+        skip_list.remove(4);
+        assert_eq!(
+            skip_list.find_prev(5),
+            Some((3, generate_values[3].clone()))
+        );
+
+        let forward: Vec<(u64, String)> = skip_list.range(0, 9).collect();
+        let mut backward: Vec<(u64, String)> = skip_list.iter_rev().collect();
+        backward.reverse();
+        assert_eq!(forward, backward);
+
+        skip_list.insert(4, "reinserted".to_string());
+        let forward: Vec<(u64, String)> = skip_list.range(0, 9).collect();
+        let mut backward: Vec<(u64, String)> = skip_list.iter_rev().collect();
+        backward.reverse();
+        assert_eq!(forward, backward);
+    }
 }